@@ -5,20 +5,32 @@
 //!
 //! ## Features
 //!
-//! - **Generic type support**: Works with any type that implements `FromStr`
-//! - **Validation**: Optional predicate-based validation with custom error messages
+//! - **Generic type support**: Works with any type that implements `FromStr`,
+//!   or with a custom `.parser()`/[`Input::with_parser`] for types that don't
+//! - **Validation**: Chainable predicate-based validation with custom error messages,
+//!   plus `.range()`/`.length()` convenience validators
 //! - **Builder pattern**: Fluent API for composing input configurations
 //! - **Error handling**: Distinguishes between format errors and validation errors
+//! - **Fallible reads**: `try_read`/`try_read_with_attempts` return an [`InputError`]
+//!   instead of looping forever, for use in tests and non-interactive pipelines
+//! - **Confirmation prompts**: [`Confirm`] reads yes/no answers directly, with
+//!   an optional default shown as `[Y/n]`
+//! - **Selection prompts**: [`Select`] prints numbered options and accepts
+//!   either an index or the option's exact text
 //!
 //! ## Examples
 //!
+//! The examples below are marked `ignore`: they read `Input`/`Confirm`/
+//! `Select` as if already in scope, which needs an import path this crate
+//! can't give yet without a `Cargo.toml` to name itself.
+//!
 //! ### Simple string input
-//! ```
+//! ```ignore
 //! let name = Input::<String>::new("Enter your name").read();
 //! ```
 //!
 //! ### Validated numeric input
-//! ```
+//! ```ignore
 //! let age = Input::<u32>::new("Enter your age")
 //!     .validate(|age| age > &0u32)
 //!     .err_msg("Age must be a positive number")
@@ -26,48 +38,118 @@
 //! ```
 //!
 //! ### With custom validation message
-//! ```
+//! ```ignore
 //! let email = Input::<String>::new("Enter email")
 //!     .validate(|email| email.contains('@'))
 //!     .err_msg("Email must contain '@' symbol")
 //!     .read();
 //! ```
+//!
+//! ### Range and length validators, chained
+//! ```ignore
+//! let age: u32 = Input::new("Enter age").range(0..=100).read();
+//!
+//! let username: String = Input::new("Username")
+//!     .length(3..=10)
+//!     .validate(|name| !name.contains(' '))
+//!     .err_msg("Username must not contain spaces")
+//!     .read();
+//! ```
+//!
+//! ### Custom parser, for types whose `FromStr` is absent or inconvenient
+//! ```ignore
+//! let rgb: u32 = Input::with_parser("Color (#RrGgBb)", |s| {
+//!     u32::from_str_radix(s.trim_start_matches('#'), 16).map_err(|e| e.to_string())
+//! })
+//! .formatter(|value| format!("#{value:06X}"))
+//! .read();
+//! ```
 
 use std::error::Error;
+use std::fmt::Debug;
+use std::ops::RangeBounds;
 use std::str::FromStr;
-use std::io::{Write, stdin, stdout};
+use std::io::{BufRead, Write, stdin, stdout};
 
-/// Reads a single line from stdin and parses it into type `T`.
-///
-/// This is an internal helper function that handles the low-level I/O and parsing.
-/// It automatically trims whitespace from the input before parsing.
+/// Errors produced by the fallible [`Input::try_read`] family of methods.
 ///
-/// # Errors
-///
-/// Returns an error if:
-/// - Reading from stdin fails
-/// - Parsing the trimmed string into type `T` fails
-///
-/// # Type Parameters
-///
-/// * `T` - Any type that implements `FromStr` with an error type that implements `Error + Send + Sync + 'static`
-///
-/// # Examples
+/// Unlike [`Input::read`], which retries forever and panics on I/O failure,
+/// `try_read`/`try_read_with_attempts` surface these as a `Result` so callers
+/// in tests or non-interactive pipelines can propagate them with `?` instead
+/// of hanging on a closed stdin.
+#[derive(Debug)]
+pub enum InputError {
+    /// The reader hit end-of-file (`read_line` returned 0 bytes) before a
+    /// valid value was produced.
+    Eof,
+    /// `read_line` itself failed (e.g. the stream contained invalid UTF-8).
+    Io(std::io::Error),
+    /// The line was read successfully but the parser rejected it.
+    Parse(String),
+    /// The parsed value repeatedly failed validation.
+    Validation {
+        /// The configured validation error message.
+        message: String,
+    },
+    /// `try_read_with_attempts` ran out of retries without ever failing to
+    /// parse or validate (only reachable when `max_attempts` is `0`).
+    TooManyAttempts {
+        /// The number of attempts made before giving up.
+        attempts: usize,
+    },
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Eof => write!(f, "unexpected end of input"),
+            InputError::Io(e) => write!(f, "failed to read input: {e}"),
+            InputError::Parse(message) => write!(f, "failed to parse input: {message}"),
+            InputError::Validation { message } => write!(f, "{message}"),
+            InputError::TooManyAttempts { attempts } => write!(f, "too many attempts ({attempts})"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A single validator: a predicate paired with the message to show when it
+/// rejects a value.
+type Validator<'a, T> = (Box<dyn Fn(&T) -> bool + 'a>, String);
+
+/// Parses a trimmed line of input into `T`, returning its own error message.
+type Parser<'a, T> = Box<dyn Fn(&str) -> Result<T, String> + 'a>;
+
+/// Formats an accepted value for echoing back to the user.
+type Formatter<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+/// Describes a `RangeBounds`' endpoints in plain language, for use in
+/// `.range()`/`.length()`'s default error messages.
 ///
-/// ```
-/// let number: u32 = _read().expect("Failed to read");
-/// let text: String = _read().expect("Failed to read");
-/// ```
-fn _read<'a, T>() -> Result<T, Box<dyn Error>>
-where 
-    T: FromStr,
-    T::Err: Error + Send + Sync + 'static 
-{
-    let mut value: String = String::new();
-    stdin().read_line(&mut value)?;
-    let trimmed = value.trim();
-    let result = trimmed.parse::<T>()?;
-    Ok(result)
+/// Avoids formatting `Bound` with `{:?}` directly, which would leak its
+/// internal `Included`/`Excluded`/`Unbounded` representation into
+/// user-facing prompt text.
+fn describe_bound_range<T: Debug>(start: std::ops::Bound<&T>, end: std::ops::Bound<&T>) -> String {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+
+    match (start, end) {
+        (Included(lo), Included(hi)) => format!("between {lo:?} and {hi:?} (inclusive)"),
+        (Included(lo), Excluded(hi)) => format!("at least {lo:?} and less than {hi:?}"),
+        (Included(lo), Unbounded) => format!("at least {lo:?}"),
+        (Excluded(lo), Included(hi)) => format!("greater than {lo:?} and at most {hi:?}"),
+        (Excluded(lo), Excluded(hi)) => format!("greater than {lo:?} and less than {hi:?}"),
+        (Excluded(lo), Unbounded) => format!("greater than {lo:?}"),
+        (Unbounded, Included(hi)) => format!("at most {hi:?}"),
+        (Unbounded, Excluded(hi)) => format!("less than {hi:?}"),
+        (Unbounded, Unbounded) => "any value".to_string(),
+    }
 }
 
 /// A builder struct for interactive user input with validation.
@@ -87,7 +169,7 @@ where
 ///
 /// # Examples
 ///
-/// ```
+/// ```ignore
 /// // Simple input without validation
 /// let name = Input::<String>::new("Enter name").read();
 ///
@@ -100,21 +182,33 @@ where
 pub struct Input<'a, T> {
     /// The prompt message displayed to the user
     msg: &'a str,
-    /// A predicate function that validates the parsed input.
-    /// Returns `true` if the input is valid, `false` otherwise.
-    predicate: Box<dyn Fn(&T) -> bool + 'a>,
-    /// Custom error message displayed when validation fails
-    err_msg: &'a str,
+    /// Validators accumulated via `.validate()`/`.range()`/`.length()`.
+    /// `read()` reports the message of the first validator that fails.
+    validators: Vec<Validator<'a, T>>,
+    /// Custom error message set via `.err_msg()`, applied to the next
+    /// validator added (including `.range()`/`.length()`'s own default
+    /// message) and retroactively to the most recently added one when set
+    /// afterwards (e.g. `.validate(pred).err_msg("...")`). `None` means no
+    /// custom message has been set, so each validator falls back to its own
+    /// default.
+    err_msg: Option<&'a str>,
+    /// Parses a trimmed line of input into `T`. Defaults to `T::from_str` in
+    /// [`Input::new`]; overridden by `.parser()` or [`Input::with_parser`]
+    /// for types whose `FromStr` is absent or inconvenient.
+    parser: Parser<'a, T>,
+    /// Optional formatter used to echo an accepted value back to the user.
+    formatter: Option<Formatter<'a, T>>,
 }
 
-impl <'a, T> Input<'a, T>
-where 
+impl<'a, T> Input<'a, T>
+where
     T: FromStr,
     T::Err: Error + Send + Sync + 'static
 {
     /// Creates a new `Input` builder with the given prompt message.
     ///
     /// By default:
+    /// - Values are parsed with `T::from_str`
     /// - No validation is applied (always accepts input)
     /// - Error message is "Entrada inválida"
     ///
@@ -128,21 +222,84 @@ where
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let input = Input::<String>::new("What's your name?");
     /// ```
     pub fn new(msg: &'a str) -> Self {
         Self {
             msg,
-            predicate: Box::new(|_| true),
-            err_msg: "Entrada inválida"
+            validators: Vec::new(),
+            err_msg: None,
+            parser: Box::new(|s: &str| s.parse::<T>().map_err(|e| e.to_string())),
+            formatter: None,
+        }
+    }
+}
+
+impl<'a, T> Input<'a, T> {
+    /// Creates a new `Input` builder that parses values with `parser` instead
+    /// of `T::from_str`.
+    ///
+    /// Unlike [`Input::new`], this does not require `T: FromStr`, so it works
+    /// for types that don't implement it at all (hex RGB codes, comma-separated
+    /// lists, custom date formats, ...).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let rgb = Input::with_parser("Color", |s| {
+    ///     u32::from_str_radix(s.trim_start_matches('#'), 16).map_err(|e| e.to_string())
+    /// }).read();
+    /// ```
+    pub fn with_parser(msg: &'a str, parser: impl Fn(&str) -> Result<T, String> + 'a) -> Self {
+        Self {
+            msg,
+            validators: Vec::new(),
+            err_msg: None,
+            parser: Box::new(parser),
+            formatter: None,
         }
     }
-    
+
+    /// Overrides how a trimmed line of input is parsed into `T`.
+    ///
+    /// The parser returns its own `String` error message instead of relying
+    /// on `T::Err: Error + Send + Sync + 'static`, which is what lets this
+    /// override types whose `FromStr` is absent or inconvenient.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let rgb = Input::<u32>::new("Color")
+    ///     .parser(|s| u32::from_str_radix(s.trim_start_matches('#'), 16).map_err(|e| e.to_string()))
+    ///     .read();
+    /// ```
+    pub fn parser(mut self, parser: impl Fn(&str) -> Result<T, String> + 'a) -> Self {
+        self.parser = Box::new(parser);
+        self
+    }
+
+    /// Sets a formatter used to echo an accepted value back to the user after
+    /// it is read.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let rgb = Input::<u32>::new("Color")
+    ///     .formatter(|value| format!("#{value:06X}"))
+    ///     .read();
+    /// ```
+    pub fn formatter(mut self, formatter: impl Fn(&T) -> String + 'a) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
     /// Adds validation logic to the input.
     ///
     /// The provided predicate is called after successful parsing. If it returns `false`,
-    /// the user is prompted to try again with the configured error message.
+    /// the user is prompted to try again with the configured error message. Validators
+    /// accumulate: each call to `.validate()`/`.range()`/`.length()` adds another check,
+    /// and `read()` reports the message of the first one that fails.
     ///
     /// # Arguments
     ///
@@ -155,7 +312,7 @@ where
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// Input::<u32>::new("Enter age")
     ///     .validate(|age| age > &18u32)
     ///     .read();
@@ -165,14 +322,67 @@ where
     ///     .read();
     /// ```
     pub fn validate(mut self, predicate: impl Fn(&T) -> bool + 'a) -> Self {
-        self.predicate = Box::new(predicate);
+        let message = self.err_msg.take().unwrap_or("Entrada inválida").to_string();
+        self.validators.push((Box::new(predicate), message));
+        self
+    }
+
+    /// Adds a validator requiring the parsed value to fall within `range`.
+    ///
+    /// Accepts any `RangeBounds<T>` (`0..=100`, `0..100`, `18..`, etc.), matching
+    /// the range syntax read_input/smart-read use for numeric prompts.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let age: u32 = Input::new("Enter age").range(0..=100).read();
+    /// ```
+    pub fn range(mut self, range: impl RangeBounds<T> + 'a) -> Self
+    where
+        T: PartialOrd + Debug,
+    {
+        let message = self
+            .err_msg
+            .take()
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("Value must be {}", describe_bound_range(range.start_bound(), range.end_bound())));
+        self.validators.push((Box::new(move |value: &T| range.contains(value)), message));
         self
     }
-    
+
+    /// Adds a validator requiring the parsed value's string length (in chars)
+    /// to fall within `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let username: String = Input::new("Username").length(3..=10).read();
+    /// ```
+    pub fn length(mut self, range: impl RangeBounds<usize> + 'a) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let message = self
+            .err_msg
+            .take()
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("Length must be {}", describe_bound_range(range.start_bound(), range.end_bound())));
+        self.validators.push((
+            Box::new(move |value: &T| range.contains(&value.as_ref().chars().count())),
+            message,
+        ));
+        self
+    }
+
     /// Sets a custom error message for validation failures.
     ///
-    /// This message is displayed when the validation predicate returns `false`.
-    /// If not set, defaults to "Entrada inválida".
+    /// Applies to the most recently added validator if one exists (so
+    /// `.validate(pred).err_msg("...")` attaches the message to `pred`), or
+    /// becomes the default message for the next validator added otherwise —
+    /// including a `.range()`/`.length()` added afterwards, which would
+    /// otherwise fall back to their own generated message. If never set,
+    /// `.validate()` defaults to "Entrada inválida", while `.range()`/
+    /// `.length()` describe the configured bounds.
     ///
     /// # Arguments
     ///
@@ -184,29 +394,108 @@ where
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// Input::<u32>::new("Enter age")
     ///     .validate(|age| age > &0u32)
     ///     .err_msg("Age must be a positive number")
     ///     .read();
     /// ```
     pub fn err_msg(mut self, err_msg: &'a str) -> Self {
-        self.err_msg = err_msg;
+        match self.validators.last_mut() {
+            Some(last) => {
+                last.1 = err_msg.to_string();
+                self.err_msg = None;
+            }
+            None => self.err_msg = Some(err_msg),
+        }
         self
     }
-    
-    /// Starts an interactive input loop and returns the validated input.
+
+    /// Runs the prompt/parse/validate loop against `reader`/`writer`, optionally
+    /// bounded to `max_attempts`.
+    ///
+    /// This is the shared core behind every `read*`/`try_read*` method. It
+    /// returns `Err(InputError::Eof)` as soon as `reader` is exhausted instead
+    /// of looping forever, and once `max_attempts` is reached it reports
+    /// whatever the last rejected attempt was: `Err(InputError::Parse)` if the
+    /// last line failed to parse, `Err(InputError::Validation)` if it parsed
+    /// but failed validation, or `Err(InputError::TooManyAttempts)` if no
+    /// attempt was ever made (i.e. `max_attempts` is `0`).
+    fn try_read_loop<R: BufRead, W: Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        max_attempts: Option<usize>,
+    ) -> Result<T, InputError> {
+        let mut attempts = 0usize;
+        let mut last_err: Option<InputError> = None;
+
+        loop {
+            if max_attempts.is_some_and(|max| attempts >= max) {
+                return Err(last_err.unwrap_or(InputError::TooManyAttempts { attempts }));
+            }
+            attempts += 1;
+
+            write!(writer, "{}: ", self.msg).expect("Failed to write prompt");
+            writer.flush().expect("Failed to flush writer");
+
+            let mut line = String::new();
+            let bytes = reader.read_line(&mut line).map_err(InputError::Io)?;
+            if bytes == 0 {
+                return Err(InputError::Eof);
+            }
+
+            let trimmed = line.trim();
+            match (self.parser)(trimmed) {
+                Ok(value) => match self.validators.iter().find(|(predicate, _)| !predicate(&value)) {
+                    None => {
+                        if let Some(formatter) = &self.formatter {
+                            writeln!(writer, "{}", formatter(&value)).expect("Failed to write echo");
+                        }
+                        return Ok(value);
+                    }
+                    Some((_, message)) => {
+                        writeln!(writer, "Error: {}", message).expect("Failed to write error");
+                        last_err = Some(InputError::Validation { message: message.clone() });
+                    }
+                },
+                Err(message) => {
+                    writeln!(writer, "Error de formato: {}", message).expect("Failed to write error");
+                    last_err = Some(InputError::Parse(message));
+                }
+            }
+        }
+    }
+
+    /// Starts an input loop against the given `reader`/`writer` and returns the validated input.
+    ///
+    /// This is the testable core behind [`Input::read`]: it contains the
+    /// prompt/parse/validate loop but is generic over any `BufRead`/`Write`
+    /// pair instead of hard-coding `stdin`/`stdout`.
+    ///
+    /// # Panics
     ///
-    /// This method:
-    /// 1. Displays the prompt message
-    /// 2. Reads a line from stdin
-    /// 3. Attempts to parse it as type `T`
-    /// 4. If parsing succeeds, validates with the predicate
-    /// 5. Loops until valid input is received
+    /// Panics if `reader` hits EOF before a valid value is produced, or if
+    /// writing to `writer` fails. Use [`Input::try_read_from`] to handle EOF
+    /// without panicking.
     ///
-    /// The method will keep prompting until either:
-    /// - Input parses successfully AND passes validation
-    /// - The user provides a valid format (for types that always validate)
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut reader = &b"John\n"[..];
+    /// let mut writer: Vec<u8> = Vec::new();
+    /// let name = Input::<String>::new("Nombre").read_from(&mut reader, &mut writer);
+    /// assert_eq!(name, "John");
+    /// ```
+    pub fn read_from<R: BufRead, W: Write>(&self, reader: &mut R, writer: &mut W) -> T {
+        self.try_read_loop(reader, writer, None)
+            .expect("Failed to read input")
+    }
+
+    /// Starts an interactive input loop against stdin/stdout and returns the validated input.
+    ///
+    /// This is a thin convenience wrapper around [`Input::read_from`] using
+    /// the process' standard input and output.
     ///
     /// # Returns
     ///
@@ -214,11 +503,13 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if stdout flushing fails (very rare in normal circumstances)
+    /// Panics if stdin hits EOF or if stdout flushing fails (very rare in
+    /// normal circumstances). Use [`Input::try_read`] to propagate these
+    /// instead of panicking.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let name: String = Input::<String>::new("Enter name").read();
     ///
     /// let age: u32 = Input::<u32>::new("Enter age")
@@ -227,16 +518,303 @@ where
     ///     .read();
     /// ```
     pub fn read(&self) -> T {
+        self.read_from(&mut stdin().lock(), &mut stdout().lock())
+    }
+
+    /// Like [`Input::read_from`], but returns `Err(InputError::Eof)` instead of
+    /// panicking when `reader` is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut reader = &b""[..];
+    /// let mut writer: Vec<u8> = Vec::new();
+    /// let err = Input::<String>::new("Nombre").try_read_from(&mut reader, &mut writer).unwrap_err();
+    /// assert!(matches!(err, InputError::Eof));
+    /// ```
+    pub fn try_read_from<R: BufRead, W: Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<T, InputError> {
+        self.try_read_loop(reader, writer, None)
+    }
+
+    /// Like [`Input::try_read_from`], but gives up after `max` attempts
+    /// instead of retrying forever, returning the last attempt's
+    /// `InputError::Parse`/`InputError::Validation` (or
+    /// `InputError::TooManyAttempts` when `max` is `0`).
+    pub fn try_read_from_with_attempts<R: BufRead, W: Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+        max: usize,
+    ) -> Result<T, InputError> {
+        self.try_read_loop(reader, writer, Some(max))
+    }
+
+    /// Fallible counterpart to [`Input::read`] for stdin/stdout.
+    ///
+    /// Returns `Err(InputError::Eof)` instead of spinning forever when stdin
+    /// is closed or exhausted, which is what makes this usable in tests and
+    /// non-interactive pipelines via `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn ask_age() -> Result<u32, InputError> {
+    ///     Input::<u32>::new("Enter age").try_read()
+    /// }
+    /// ```
+    pub fn try_read(&self) -> Result<T, InputError> {
+        self.try_read_from(&mut stdin().lock(), &mut stdout().lock())
+    }
+
+    /// Fallible counterpart to [`Input::read`] that caps the number of attempts.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let age = Input::<u32>::new("Enter age").try_read_with_attempts(3)?;
+    /// ```
+    pub fn try_read_with_attempts(&self, max: usize) -> Result<T, InputError> {
+        self.try_read_from_with_attempts(&mut stdin().lock(), &mut stdout().lock(), max)
+    }
+}
+
+impl<'a> Input<'a, bool> {
+    /// Converts this builder into a dedicated yes/no [`Confirm`] prompt.
+    ///
+    /// Useful because `bool`'s `FromStr` only accepts the literal strings
+    /// `"true"`/`"false"`, whereas a confirmation prompt should also accept
+    /// `y`/`yes`/`n`/`no`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let proceed = Input::<bool>::new("Proceed?").confirm().read();
+    /// ```
+    pub fn confirm(self) -> Confirm<'a> {
+        Confirm::new(self.msg)
+    }
+}
+
+/// A yes/no confirmation prompt.
+///
+/// Accepts `y`/`yes`/`n`/`no`/`true`/`false` case-insensitively by default
+/// (the affirmative/negative word sets can be overridden), and parses the
+/// answer directly instead of going through `FromStr`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let proceed = Confirm::new("Proceed?").default(true).read();
+/// ```
+pub struct Confirm<'a> {
+    /// The prompt message displayed to the user
+    msg: &'a str,
+    /// The value returned when the user submits an empty line, if any
+    default: Option<bool>,
+    /// Lowercased words accepted as "yes"
+    affirmative: Vec<String>,
+    /// Lowercased words accepted as "no"
+    negative: Vec<String>,
+}
+
+impl<'a> Confirm<'a> {
+    /// Creates a new `Confirm` prompt with the given message.
+    ///
+    /// By default accepts `y`/`yes`/`true` as affirmative and `n`/`no`/`false`
+    /// as negative (case-insensitively), with no default answer.
+    pub fn new(msg: &'a str) -> Self {
+        Self {
+            msg,
+            default: None,
+            affirmative: vec!["y".to_string(), "yes".to_string(), "true".to_string()],
+            negative: vec!["n".to_string(), "no".to_string(), "false".to_string()],
+        }
+    }
+
+    /// Sets the value returned when the user submits an empty line, and
+    /// reflects it in the prompt as `[Y/n]`/`[y/N]`.
+    pub fn default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Overrides the words accepted as an affirmative answer (case-insensitive).
+    pub fn affirmative(mut self, words: &[&str]) -> Self {
+        self.affirmative = words.iter().map(|w| w.to_lowercase()).collect();
+        self
+    }
+
+    /// Overrides the words accepted as a negative answer (case-insensitive).
+    pub fn negative(mut self, words: &[&str]) -> Self {
+        self.negative = words.iter().map(|w| w.to_lowercase()).collect();
+        self
+    }
+
+    /// The `[Y/n]`/`[y/N]`/`[y/n]` suffix appended to the prompt, reflecting
+    /// the configured default.
+    fn hint(&self) -> &'static str {
+        match self.default {
+            Some(true) => " [Y/n]",
+            Some(false) => " [y/N]",
+            None => " [y/n]",
+        }
+    }
+
+    /// Runs the prompt/parse loop against `reader`/`writer` and returns the
+    /// confirmed answer.
+    ///
+    /// Loops until the user answers with one of the affirmative/negative
+    /// words, or submits an empty line when a default is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reader` hits EOF before a valid answer is produced, or if
+    /// writing to `writer` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut reader = &b"yes\n"[..];
+    /// let mut writer: Vec<u8> = Vec::new();
+    /// let proceed = Confirm::new("Proceed?").read_from(&mut reader, &mut writer);
+    /// assert!(proceed);
+    /// ```
+    pub fn read_from<R: BufRead, W: Write>(&self, reader: &mut R, writer: &mut W) -> bool {
+        loop {
+            write!(writer, "{}{}: ", self.msg, self.hint()).expect("Failed to write prompt");
+            writer.flush().expect("Failed to flush writer");
+
+            let mut line = String::new();
+            let bytes = reader.read_line(&mut line).expect("Failed to read line");
+            if bytes == 0 {
+                panic!("unexpected end of input");
+            }
+            let trimmed = line.trim().to_lowercase();
+
+            if trimmed.is_empty() {
+                if let Some(default) = self.default {
+                    return default;
+                }
+            } else if self.affirmative.iter().any(|word| word == &trimmed) {
+                return true;
+            } else if self.negative.iter().any(|word| word == &trimmed) {
+                return false;
+            }
+
+            writeln!(writer, "Error: Responde con 'y' o 'n'").expect("Failed to write error");
+        }
+    }
+
+    /// Starts an interactive confirmation prompt against stdin/stdout.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let proceed = Confirm::new("Proceed?").read();
+    /// ```
+    pub fn read(&self) -> bool {
+        self.read_from(&mut stdin().lock(), &mut stdout().lock())
+    }
+}
+
+/// A select-from-options prompt.
+///
+/// Prints the given `options` numbered from 1, reads either a 1-based index
+/// or an exact match of one option's text, and returns both the chosen
+/// index and the option itself.
+///
+/// # Examples
+///
+/// ```ignore
+/// let (index, color) = Select::new("Pick a color", &["red", "green", "blue"]).read();
+/// ```
+pub struct Select<'a> {
+    /// The prompt message displayed above the numbered options
+    msg: &'a str,
+    /// The options to choose from, numbered from 1 in display order
+    options: &'a [&'a str],
+}
+
+impl<'a> Select<'a> {
+    /// Creates a new `Select` prompt with the given message and options.
+    pub fn new(msg: &'a str, options: &'a [&'a str]) -> Self {
+        Self { msg, options }
+    }
+
+    /// Writes the prompt message followed by the numbered options.
+    fn print_options<W: Write>(&self, writer: &mut W) {
+        writeln!(writer, "{}", self.msg).expect("Failed to write prompt");
+        for (index, option) in self.options.iter().enumerate() {
+            writeln!(writer, "  {}. {}", index + 1, option).expect("Failed to write option");
+        }
+    }
+
+    /// Runs the prompt/parse loop against `reader`/`writer` and returns the
+    /// chosen option's index (0-based) and text.
+    ///
+    /// Accepts either a 1-based index into `options` or an exact match of an
+    /// option's text, and loops until one of those is given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `reader` hits EOF before a valid choice is produced, or if
+    /// writing to `writer` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut reader = &b"2\n"[..];
+    /// let mut writer: Vec<u8> = Vec::new();
+    /// let (index, color) = Select::new("Pick a color", &["red", "green", "blue"])
+    ///     .read_from(&mut reader, &mut writer);
+    /// assert_eq!((index, color), (1, "green"));
+    /// ```
+    pub fn read_from<R: BufRead, W: Write>(&self, reader: &mut R, writer: &mut W) -> (usize, &'a str) {
+        self.print_options(writer);
         loop {
-            print!("{}: ", self.msg);
-            stdout().flush().expect("Failed to flush stdout");
-            match _read::<T>() {
-                Ok(input) if (self.predicate)(&input) => return input,
-                Ok(_) => println!("Error: {}", self.err_msg),
-                Err(e) => println!("Error de formato: {}", e),
+            write!(writer, "> ").expect("Failed to write prompt");
+            writer.flush().expect("Failed to flush writer");
+
+            let mut line = String::new();
+            let bytes = reader.read_line(&mut line).expect("Failed to read line");
+            if bytes == 0 {
+                panic!("unexpected end of input");
+            }
+            let trimmed = line.trim();
+
+            let chosen = trimmed
+                .parse::<usize>()
+                .ok()
+                .filter(|index| *index >= 1 && *index <= self.options.len())
+                .map(|index| index - 1)
+                .or_else(|| self.options.iter().position(|option| *option == trimmed));
+
+            match chosen {
+                Some(index) => return (index, self.options[index]),
+                None => writeln!(
+                    writer,
+                    "Error: Elige un número entre 1 y {} o el texto de una opción",
+                    self.options.len()
+                )
+                .expect("Failed to write error"),
             }
         }
     }
+
+    /// Starts an interactive select prompt against stdin/stdout.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let (index, color) = Select::new("Pick a color", &["red", "green", "blue"]).read();
+    /// ```
+    pub fn read(&self) -> (usize, &'a str) {
+        self.read_from(&mut stdin().lock(), &mut stdout().lock())
+    }
 }
 
 #[cfg(test)]
@@ -245,41 +823,51 @@ mod tests {
 
     /// Tests basic string input without validation.
     ///
-    /// Verifies that `Input` can read and parse a string value.
+    /// Verifies that `Input` can read and parse a string value from an
+    /// injected reader, and that the prompt is written to the writer.
     #[test]
     fn it_works_simple() {
-        
+        let mut reader = &b"John\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
         let result: String = Input::new("Nombre")
-                .read();
-        
+                .read_from(&mut reader, &mut writer);
+
         assert_eq!(result, "John");
+        assert_eq!(String::from_utf8(writer).unwrap(), "Nombre: ");
     }
-    
+
     /// Tests string input with length validation.
     ///
     /// Verifies that validation rules are properly enforced.
     #[test]
     fn it_works_with_str() {
-        
+        let mut reader = &b"John\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
         let result = Input::<String>::new("Nombre")
                 .validate(|name| name.len() > 3)
-                .read();
-        
+                .read_from(&mut reader, &mut writer);
+
         assert_eq!(result, "John");
     }
-    
+
     /// Tests string input with validation and custom error message.
     ///
-    /// Verifies that custom error messages are correctly configured.
+    /// Verifies that custom error messages are correctly configured and
+    /// written to the writer when the first attempt is invalid.
     #[test]
     fn it_works_with_str_and_err_msg() {
-        
+        let mut reader = &b"Al\nJohn\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
         let result = Input::<String>::new("Nombre")
                 .validate(|name| name.len() > 3)
                 .err_msg("Ha de contener como mínimo 4 letras")
-                .read();
-        
+                .read_from(&mut reader, &mut writer);
+
         assert_eq!(result, "John");
+        assert!(String::from_utf8(writer).unwrap().contains("Ha de contener como mínimo 4 letras"));
     }
 
     /// Tests numeric input with value validation.
@@ -288,11 +876,320 @@ mod tests {
     /// and that validation constraints are applied.
     #[test]
     fn it_works_with_number() {
-        
+        let mut reader = &b"25\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
         let result = Input::<u32>::new("Edad")
             .validate(|age| age > &0u32)
             .err_msg("Debe ser un número positivo")
-            .read();
+            .read_from(&mut reader, &mut writer);
         assert_eq!(result, 25);
     }
+
+    /// Tests that `try_read` reports EOF instead of looping forever when the
+    /// reader runs out of lines.
+    #[test]
+    fn try_read_reports_eof() {
+        let mut reader = &b""[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<String>::new("Nombre").try_read_from(&mut reader, &mut writer);
+
+        assert!(matches!(result, Err(InputError::Eof)));
+    }
+
+    /// Tests that `try_read_with_attempts` gives up after the configured
+    /// number of tries instead of retrying forever, reporting the last
+    /// parse failure rather than a generic `TooManyAttempts`.
+    #[test]
+    fn try_read_with_attempts_gives_up() {
+        let mut reader = &b"abc\ndef\nghi\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<u32>::new("Edad").try_read_from_with_attempts(&mut reader, &mut writer, 2);
+
+        assert!(matches!(result, Err(InputError::Parse(_))));
+    }
+
+    /// Tests that repeated validation failures are reported as
+    /// `InputError::Validation` once attempts run out.
+    #[test]
+    fn try_read_with_attempts_reports_validation_failure() {
+        let mut reader = &b"-1\n-2\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<i32>::new("Edad")
+            .validate(|age| *age >= 0)
+            .err_msg("Debe ser un número positivo")
+            .try_read_from_with_attempts(&mut reader, &mut writer, 2);
+
+        assert!(matches!(result, Err(InputError::Validation { message }) if message == "Debe ser un número positivo"));
+    }
+
+    /// Tests that `try_read_with_attempts(0)` gives up before ever reading a
+    /// line, when there is no parse/validation failure to report instead.
+    #[test]
+    fn try_read_with_zero_attempts_reports_too_many_attempts() {
+        let mut reader = &b"42\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<u32>::new("Edad").try_read_from_with_attempts(&mut reader, &mut writer, 0);
+
+        assert!(matches!(result, Err(InputError::TooManyAttempts { attempts: 0 })));
+    }
+
+    /// Tests that `.range()` rejects out-of-range values before accepting one
+    /// inside the range.
+    #[test]
+    fn range_validator_rejects_out_of_range() {
+        let mut reader = &b"150\n50\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<u32>::new("Edad").range(0..=100).read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, 50);
+    }
+
+    /// Tests that `.length()` rejects strings outside the allowed length.
+    #[test]
+    fn length_validator_rejects_wrong_length() {
+        let mut reader = &b"ab\njohndoe\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<String>::new("Username")
+            .length(3..=10)
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, "johndoe");
+    }
+
+    /// Tests that `.err_msg()` set before `.range()`/`.length()` overrides
+    /// their generated default message, instead of being silently dropped.
+    #[test]
+    fn err_msg_before_range_overrides_default_message() {
+        let mut reader = &b"150\n50\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<u32>::new("Edad")
+            .err_msg("Must be 0-100")
+            .range(0..=100)
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, 50);
+        assert!(String::from_utf8(writer).unwrap().contains("Must be 0-100"));
+    }
+
+    /// Tests that `.err_msg()` only applies to the validator it follows, not
+    /// to validators added afterward.
+    #[test]
+    fn err_msg_does_not_leak_into_later_validators() {
+        let mut reader = &b"2000\n500\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<i32>::new("Num")
+            .validate(|n| *n >= 0)
+            .err_msg("must be non-negative")
+            .validate(|n| *n < 1000)
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, 500);
+        let output = String::from_utf8(writer).unwrap();
+        assert!(!output.contains("must be non-negative"));
+        assert!(output.contains("Entrada inválida"));
+    }
+
+    /// Tests that `.range()`'s default message describes the bounds in plain
+    /// language instead of leaking `Bound`'s `{:?}` representation.
+    #[test]
+    fn range_default_message_does_not_leak_debug_repr() {
+        let mut reader = &b"150\n50\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        Input::<u32>::new("Edad").range(0..=100).read_from(&mut reader, &mut writer);
+
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.contains("between 0 and 100"));
+        assert!(!output.contains("Included"));
+    }
+
+    /// Tests that validators accumulate and `read()` reports the message of
+    /// the first one that fails, rather than overwriting it.
+    #[test]
+    fn validators_accumulate_and_report_first_failure() {
+        let mut reader = &b"ab\nabcdefghijk\nJohn Doe\njohndoe\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<String>::new("Username")
+            .length(3..=10)
+            .err_msg("Length must be 3-10 characters")
+            .validate(|name| !name.contains(' '))
+            .err_msg("Username must not contain spaces")
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, "johndoe");
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.contains("Length must be 3-10 characters"));
+        assert!(output.contains("Username must not contain spaces"));
+    }
+
+    /// Tests that `Confirm` accepts the default affirmative/negative words
+    /// case-insensitively.
+    #[test]
+    fn confirm_accepts_yes_no_case_insensitively() {
+        let mut reader = &b"YES\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+        assert!(Confirm::new("Proceed?").read_from(&mut reader, &mut writer));
+
+        let mut reader = &b"No\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+        assert!(!Confirm::new("Proceed?").read_from(&mut reader, &mut writer));
+    }
+
+    /// Tests that an empty answer falls back to the configured default, and
+    /// that the default is reflected in the prompt.
+    #[test]
+    fn confirm_uses_default_on_empty_input() {
+        let mut reader = &b"\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Confirm::new("Proceed?").default(true).read_from(&mut reader, &mut writer);
+
+        assert!(result);
+        assert_eq!(String::from_utf8(writer).unwrap(), "Proceed? [Y/n]: ");
+    }
+
+    /// Tests that invalid answers are rejected and re-prompted until a valid
+    /// one is given.
+    #[test]
+    fn confirm_reprompts_on_invalid_answer() {
+        let mut reader = &b"maybe\ny\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Confirm::new("Proceed?").read_from(&mut reader, &mut writer);
+
+        assert!(result);
+        assert!(String::from_utf8(writer).unwrap().contains("Error:"));
+    }
+
+    /// Tests that `Confirm::read_from` panics (instead of looping forever)
+    /// when `reader` hits EOF before a valid answer is produced.
+    #[test]
+    #[should_panic(expected = "unexpected end of input")]
+    fn confirm_panics_on_eof() {
+        let mut reader = &b""[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        Confirm::new("Proceed?").read_from(&mut reader, &mut writer);
+    }
+
+    /// Tests that `Input::<bool>::confirm()` produces an equivalent `Confirm` prompt.
+    #[test]
+    fn input_bool_confirm_delegates_to_confirm() {
+        let mut reader = &b"n\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<bool>::new("Proceed?").confirm().read_from(&mut reader, &mut writer);
+
+        assert!(!result);
+    }
+
+    /// Tests that `Select` accepts a 1-based index and returns the matching option.
+    #[test]
+    fn select_accepts_index() {
+        let mut reader = &b"2\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Select::new("Pick a color", &["red", "green", "blue"])
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, (1, "green"));
+    }
+
+    /// Tests that `Select` also accepts the exact text of an option.
+    #[test]
+    fn select_accepts_exact_text() {
+        let mut reader = &b"blue\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Select::new("Pick a color", &["red", "green", "blue"])
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, (2, "blue"));
+    }
+
+    /// Tests that out-of-range indices and unknown text are rejected and
+    /// re-prompted until a valid choice is given.
+    #[test]
+    fn select_reprompts_on_invalid_choice() {
+        let mut reader = &b"0\npurple\n1\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Select::new("Pick a color", &["red", "green", "blue"])
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, (0, "red"));
+        let output = String::from_utf8(writer).unwrap();
+        assert_eq!(output.matches("Error:").count(), 2);
+    }
+
+    /// Tests that `Select::read_from` panics (instead of looping forever)
+    /// when `reader` hits EOF before a valid choice is produced.
+    #[test]
+    #[should_panic(expected = "unexpected end of input")]
+    fn select_panics_on_eof() {
+        let mut reader = &b""[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        Select::new("Pick a color", &["red", "green", "blue"]).read_from(&mut reader, &mut writer);
+    }
+
+    /// Tests that `.parser()` overrides the default `FromStr`-based parsing,
+    /// retrying on a custom error message.
+    #[test]
+    fn custom_parser_overrides_from_str() {
+        let mut reader = &b"zz\n#ff0000\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<u32>::new("Color")
+            .parser(|s| u32::from_str_radix(s.trim_start_matches('#'), 16).map_err(|e| e.to_string()))
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, 0xff0000);
+        assert!(String::from_utf8(writer).unwrap().contains("Error de formato:"));
+    }
+
+    /// Tests that `Input::with_parser` works for a type with no `FromStr` impl.
+    #[test]
+    fn with_parser_supports_types_without_from_str() {
+        #[derive(Debug, PartialEq)]
+        struct Rgb(u8, u8, u8);
+
+        let mut reader = &b"10,20,30\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::with_parser("Color", |s| {
+            let mut parts = s.split(',');
+            let r = parts.next().ok_or("missing red")?.parse().map_err(|_| "invalid red")?;
+            let g = parts.next().ok_or("missing green")?.parse().map_err(|_| "invalid green")?;
+            let b = parts.next().ok_or("missing blue")?.parse().map_err(|_| "invalid blue")?;
+            Ok(Rgb(r, g, b))
+        })
+        .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, Rgb(10, 20, 30));
+    }
+
+    /// Tests that `.formatter()` echoes the accepted value back to the writer.
+    #[test]
+    fn formatter_echoes_accepted_value() {
+        let mut reader = &b"255\n"[..];
+        let mut writer: Vec<u8> = Vec::new();
+
+        let result = Input::<u32>::new("Color")
+            .formatter(|value| format!("#{value:06X}"))
+            .read_from(&mut reader, &mut writer);
+
+        assert_eq!(result, 255);
+        assert!(String::from_utf8(writer).unwrap().contains("#0000FF"));
+    }
 }